@@ -1,12 +1,12 @@
 use starknet::ContractAddress;
 
 #[starknet::interface]
-trait GutsTrait<T> {
+pub trait GutsTrait<T> {
     fn verify_signed_commit(ref self: T, pub_key: u256, msg: Span<u8>, signature: Span<u256>);
 }
 
 #[starknet::contract]
-mod Guts {
+pub mod Guts {
     use super::ContractAddress;
     use starknet::get_caller_address;
     use alexandria_math::ed25519::{p, Point, verify_signature};
@@ -14,15 +14,17 @@ mod Guts {
 
     #[event]
     #[derive(Drop, starknet::Event)]
-    enum Event {
+    pub enum Event {
         GitCommitVerified: GitCommitVerified,
     }
 
     #[derive(Drop, starknet::Event)]
-    struct GitCommitVerified {
+    pub struct GitCommitVerified {
         #[key]
-        starknet_address: ContractAddress,
-        signature: Span<u8>,
+        pub starknet_address: ContractAddress,
+        #[key]
+        pub pub_key: u256,
+        pub signature: Span<u256>,
     }
 
     #[storage]
@@ -40,6 +42,12 @@ mod Guts {
         ) {
             let is_valid: bool = verify_signature(msg, signature, pub_key);
             assert!(is_valid, "Invalid signature");
+            self
+                .emit(
+                    GitCommitVerified {
+                        starknet_address: get_caller_address(), pub_key, signature
+                    }
+                );
         }
     }
 
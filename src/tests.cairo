@@ -1,4 +1,23 @@
 use alexandria_math::ed25519::{p, Point, verify_signature};
+use guts::contracts::guts::{Guts, GutsTraitSafeDispatcher, GutsTraitSafeDispatcherTrait};
+use snforge_std::{
+    declare, ContractClassTrait, spy_events, SpyOn, EventFetcher, EventAssertions,
+    start_prank, CheatTarget
+};
+use starknet::{ContractAddress, contract_address_const};
+
+const PUB_KEY: u256 = 0x26cd99663f8fcd42ea8a68aaf69bb811d3d0193aff830ce874527eae0adb8a9e;
+const R_SIGN: u256 = 0x8be5e9fac46d8fd1921d3f001e74e00afb39fd4935124bc49e223ccf7eb74db1;
+const S_SIGN: u256 = 0x8c1d3c4769f499517347b66b3b19042f8af8752703aee4e00da2e97b8d566702;
+
+fn msg() -> Span<u8> {
+    array![0x01, 0x02, 0x03, 0x04, 0xab, 0xcd, 0xef, 0xaa].span()
+}
+
+fn deploy_guts() -> ContractAddress {
+    let contract = declare('Guts');
+    contract.deploy(@array![]).unwrap()
+}
 
 #[test]
 fn test_simple_ed25519_signature() {
@@ -13,3 +32,51 @@ fn test_simple_ed25519_signature() {
 
     assert!(is_valid, "works");
 }
+
+#[test]
+fn test_verify_signed_commit_emits_event() {
+    let contract_address = deploy_guts();
+    let dispatcher = GutsTraitSafeDispatcher { contract_address };
+    let caller = contract_address_const::<'caller'>();
+    start_prank(CheatTarget::One(contract_address), caller);
+    let mut spy = spy_events(SpyOn::One(contract_address));
+
+    let signature = array![R_SIGN, S_SIGN].span();
+    dispatcher.verify_signed_commit(PUB_KEY, msg(), signature).unwrap();
+
+    spy.fetch_events();
+    assert_eq!(spy.events.len(), 1, "expected exactly one event");
+    spy
+        .assert_emitted(
+            @array![
+                (
+                    contract_address,
+                    Guts::Event::GitCommitVerified(
+                        Guts::GitCommitVerified {
+                            starknet_address: caller, pub_key: PUB_KEY, signature
+                        }
+                    )
+                )
+            ]
+        );
+}
+
+#[test]
+fn test_verify_signed_commit_rejects_tampered_signature() {
+    let contract_address = deploy_guts();
+    let dispatcher = GutsTraitSafeDispatcher { contract_address };
+    let mut spy = spy_events(SpyOn::One(contract_address));
+
+    let signature = array![R_SIGN, S_SIGN + 1].span();
+    match dispatcher.verify_signed_commit(PUB_KEY, msg(), signature) {
+        Result::Ok(_) => panic!("tampered signature was accepted"),
+        // Byte array panic data: [magic, full words count, pending word, pending word length].
+        Result::Err(panic_data) => {
+            assert_eq!(panic_data.len(), 4);
+            assert_eq!(*panic_data.at(2), 'Invalid signature');
+        },
+    }
+
+    spy.fetch_events();
+    assert_eq!(spy.events.len(), 0, "no event expected");
+}